@@ -0,0 +1,52 @@
+use std::convert::Infallible;
+
+use async_graphql::*;
+use async_graphql_extras::graphql_connection;
+use warp::Filter;
+
+type MySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+struct Query;
+
+#[derive(SimpleObject, Clone)]
+pub struct User {
+    id: String,
+}
+
+// Generates `UserConnection`, `UserEdge`, `UserPageInfo`, and `UserConnection::build(..)`
+graphql_connection!(User);
+
+#[Object]
+impl Query {
+    /// Returns a page of users
+    async fn users(
+        &self,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> UserConnection {
+        let all_users: Vec<User> = (0..50)
+            .map(|i| User {
+                id: format!("user-{}", i),
+            })
+            .collect();
+
+        UserConnection::build(all_users, first, after, last, before)
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema).and_then(
+        |(schema, request): (MySchema, async_graphql::Request)| async move {
+            // Execute query
+            let resp = schema.execute(request).await;
+
+            // Return result
+            Ok::<_, Infallible>(async_graphql_warp::Response::from(resp))
+        },
+    );
+    warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+}
@@ -0,0 +1,48 @@
+use std::convert::Infallible;
+
+use async_graphql::*;
+use async_graphql_extras::graphql_object;
+use warp::Filter;
+
+type MySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+struct Query;
+
+/// A background job
+#[graphql_object]
+pub struct Job {
+    name: String,
+
+    // `duration` is stored as an `i32`; `duration_secs` exposes the same value converted to an
+    // `i64` via the field's `Into` bound, without duplicating the stored value. A real-world
+    // field would typically be a newtype (e.g. wrapping a `chrono::Duration`) with its own
+    // `Into` impls for each representation it should expose.
+    #[graphql_object(derived(name = "duration_secs", into = "i64"))]
+    duration: i32,
+}
+
+#[Object]
+impl Query {
+    /// A single example job
+    async fn job(&self) -> Job {
+        Job {
+            name: "Ship the release".to_string(),
+            duration: 90,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema).and_then(
+        |(schema, request): (MySchema, async_graphql::Request)| async move {
+            // Execute query
+            let resp = schema.execute(request).await;
+
+            // Return result
+            Ok::<_, Infallible>(async_graphql_warp::Response::from(resp))
+        },
+    );
+    warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+}
@@ -0,0 +1,47 @@
+use std::convert::Infallible;
+
+use async_graphql::*;
+use async_graphql_extras::graphql_object;
+use warp::Filter;
+
+type MySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+struct Query;
+
+/// A user's profile
+#[graphql_object(
+    // The input object renames every field to camelCase, independent of the output type
+    rename_fields = "camelCase"
+)]
+pub struct UserProfile {
+    // Already camelCase on output; `input_name` gives the input object its own, differently
+    // cased name instead of colliding with the container-level `rename_fields` rewrite
+    #[graphql_object(input_name = "display_name")]
+    #[graphql(name = "displayName")]
+    display_name: String,
+
+    bio: String,
+}
+
+#[Object]
+impl Query {
+    /// Echoes back the submitted profile
+    async fn update_profile(&self, profile_input: UserProfileInput) -> UserProfile {
+        profile_input.into()
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema).and_then(
+        |(schema, request): (MySchema, async_graphql::Request)| async move {
+            // Execute query
+            let resp = schema.execute(request).await;
+
+            // Return result
+            Ok::<_, Infallible>(async_graphql_warp::Response::from(resp))
+        },
+    );
+    warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+}
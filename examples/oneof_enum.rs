@@ -0,0 +1,55 @@
+use std::convert::Infallible;
+
+use async_graphql::*;
+use async_graphql_extras::graphql_object;
+use warp::Filter;
+
+type MySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+struct Query;
+
+/// Matches users by an exact username
+#[graphql_object]
+pub struct ByUsername {
+    username: String,
+}
+
+/// Matches users older than the given age
+#[graphql_object]
+pub struct ByAge {
+    min_age: i32,
+}
+
+/// A single search filter: exactly one of these may be set at a time
+///
+/// Each variant wraps a `#[graphql_object]`-generated payload, so the `Union` output side uses
+/// `ByUsername`/`ByAge` while the `OneofObject` input side must point at the generated
+/// `ByUsernameInput`/`ByAgeInput` types instead, via `input_type`.
+#[graphql_object]
+pub enum UserFilter {
+    ByUsername(#[graphql_object(input_type = "ByUsernameInput")] ByUsername),
+    ByAge(#[graphql_object(input_type = "ByAgeInput")] ByAge),
+}
+
+#[Object]
+impl Query {
+    /// Echoes back the filter that was supplied
+    async fn describe_filter(&self, filter: UserFilterInput) -> UserFilter {
+        filter.into()
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema).and_then(
+        |(schema, request): (MySchema, async_graphql::Request)| async move {
+            // Execute query
+            let resp = schema.execute(request).await;
+
+            // Return result
+            Ok::<_, Infallible>(async_graphql_warp::Response::from(resp))
+        },
+    );
+    warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+}
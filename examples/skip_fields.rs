@@ -0,0 +1,55 @@
+use std::convert::Infallible;
+
+use async_graphql::*;
+use async_graphql_extras::graphql_object;
+use warp::Filter;
+
+type MySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+struct Query;
+
+fn default_id() -> String {
+    "generated-id".to_string()
+}
+
+/// A user record
+#[graphql_object]
+pub struct UserRecord {
+    // Server-assigned, so it's dropped from the input object and filled in by `default_id`
+    // when converting `UserRecordInput` back into `UserRecord`.
+    #[graphql_object(skip_input, input_default = "default_id")]
+    id: String,
+
+    // Computed at query time; falls back to `String`'s `Default` since there's no `input_default`
+    #[graphql_object(skip_input)]
+    display_name: String,
+
+    // Write-only; never rendered back to clients
+    #[graphql_object(skip_output)]
+    password: String,
+
+    username: String,
+}
+
+#[Object]
+impl Query {
+    /// Registers a user, dropping the supplied password from the response
+    async fn register(&self, user_input: UserRecordInput) -> UserRecord {
+        user_input.into()
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema).and_then(
+        |(schema, request): (MySchema, async_graphql::Request)| async move {
+            // Execute query
+            let resp = schema.execute(request).await;
+
+            // Return result
+            Ok::<_, Infallible>(async_graphql_warp::Response::from(resp))
+        },
+    );
+    warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+}
@@ -0,0 +1,48 @@
+use std::convert::{Infallible, TryInto};
+
+use async_graphql::*;
+use async_graphql_extras::graphql_object;
+use warp::Filter;
+
+type MySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+struct Query;
+
+fn validate_email(email: &String) -> Result<(), String> {
+    if email.contains('@') {
+        Ok(())
+    } else {
+        Err(format!("`{}` is not a valid email address", email))
+    }
+}
+
+/// A user's contact details
+#[graphql_object(try_into)]
+pub struct ContactInfo {
+    #[graphql_object(validate_with = "validate_email")]
+    email: String,
+    phone: String,
+}
+
+#[Object]
+impl Query {
+    /// Validates the supplied contact info, rejecting malformed emails
+    async fn set_contact_info(&self, contact_input: ContactInfoInput) -> Result<ContactInfo> {
+        Ok(contact_input.try_into()?)
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema).and_then(
+        |(schema, request): (MySchema, async_graphql::Request)| async move {
+            // Execute query
+            let resp = schema.execute(request).await;
+
+            // Return result
+            Ok::<_, Infallible>(async_graphql_warp::Response::from(resp))
+        },
+    );
+    warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+}
@@ -63,7 +63,10 @@ use darling::{FromField, FromMeta};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, AttributeArgs, ItemStruct, Path, TypePath};
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, AttributeArgs, Fields, Item, ItemEnum,
+    ItemStruct, Meta, NestedMeta, Path, Type, TypePath,
+};
 
 /// Options to the [`graphql_object`] macro
 #[derive(Debug, FromMeta, Default)]
@@ -77,6 +80,15 @@ struct GraphqlObjectMetaArgs {
 
     /// Skips deriving `SimpleObject` on the struct so that the user can do it manually
     skip_derive_simple_object: bool,
+
+    /// Generates `impl TryFrom<Input> for Original` with `Error = async_graphql::Error` instead
+    /// of an infallible `impl Into<Original> for Input`, so that field conversions and
+    /// `validate_with` checks can reject invalid input
+    try_into: bool,
+
+    /// Sets `#[graphql(rename_fields = "...")]` on the generated `InputObject` only, so it can
+    /// follow GraphQL input-naming conventions independently of the output type
+    rename_fields: Option<String>,
 }
 
 /// Options on fields for the [`graphql_object`] macro
@@ -87,6 +99,55 @@ struct GraphqlObjectFieldArgs {
     /// `Into` for the non-input object version of the type.
     #[darling(default)]
     input_type: Option<Path>,
+
+    /// Sets this field's `#[graphql(name = "...")]` on the generated `InputObject` only, leaving
+    /// the output field's name untouched. Replaces any `name` the field already carries via a
+    /// plain `#[graphql(...)]` attribute rather than appending a second, conflicting one.
+    ///
+    /// There's no separate per-field `input_rename`/`output_only`/`input_only` attribute set:
+    /// container-wide casing is covered by [`GraphqlObjectMetaArgs::rename_fields`], and routing
+    /// a field to only one side is covered by [`skip_input`](Self::skip_input) and
+    /// [`skip_output`](Self::skip_output).
+    #[darling(default)]
+    input_name: Option<String>,
+
+    /// Leaves this field out of the generated `InputObject`
+    #[darling(default)]
+    skip_input: bool,
+
+    /// Leaves this field out of the generated `SimpleObject`
+    #[darling(default)]
+    skip_output: bool,
+
+    /// A zero-argument function path used to produce this field's value when it is skipped in
+    /// the input object. Falls back to `Default::default()`, which requires the field's type to
+    /// implement `Default`.
+    #[darling(default)]
+    input_default: Option<Path>,
+
+    /// Path to a `fn(&T) -> Result<(), String>` run against this field's input value before
+    /// conversion. Only takes effect when the container uses `#[graphql_object(try_into)]`;
+    /// a returned `Err` is surfaced as a GraphQL error.
+    #[darling(default)]
+    validate_with: Option<Path>,
+
+    /// Exposes one or more extra GraphQL fields on the output `SimpleObject` that convert this
+    /// field's value `Into` another type, e.g.
+    /// `#[graphql_object(derived(name = "duration_secs", into = "i64"))]`.
+    #[darling(default, multiple, rename = "derived")]
+    derived: Vec<DerivedFieldArgs>,
+}
+
+/// A single extra GraphQL field generated from a `derived` field attribute
+#[derive(Debug, Clone, FromMeta)]
+struct DerivedFieldArgs {
+    /// The name of the generated field
+    name: String,
+
+    /// The type to convert the base field's value into. The resolver clones the base field to
+    /// convert it (`self` is only borrowed), so the base field's type must implement `Clone` in
+    /// addition to `Into<into>`.
+    into: Type,
 }
 
 /// Take a result and return token stream errors if it is an error
@@ -101,7 +162,17 @@ macro_rules! handle_darling_errors {
     };
 }
 
-/// An attribute macro that will derive both a [`SimpleObject`] and an [`InputObject`] for a struct
+/// An attribute macro that will derive both a [`SimpleObject`] and an [`InputObject`] for a
+/// struct, or a [`Union`] and a [`OneofObject`] for an enum
+///
+/// A field's own `#[graphql(...)]` attribute is copied to both the output and input copies
+/// as-is, except for `name`, which [`input_name`](GraphqlObjectFieldArgs::input_name) can
+/// override on the input side only. Other keys (e.g. `deprecation`, `visible`) are left alone
+/// on purpose: they're meaningful on both a `SimpleObject` and an `InputObject` field, so there's
+/// nothing to rewrite per side. Fields that genuinely don't belong on one side at all should use
+/// [`skip_input`](GraphqlObjectFieldArgs::skip_input) or
+/// [`skip_output`](GraphqlObjectFieldArgs::skip_output) instead of an attribute that's only
+/// valid for one of the two derives.
 #[proc_macro_attribute]
 pub fn graphql_object(args: TokenStream, input: TokenStream) -> TokenStream {
     // Parse attributes
@@ -109,43 +180,183 @@ pub fn graphql_object(args: TokenStream, input: TokenStream) -> TokenStream {
     // Get macro options from parsed attributes
     let options = handle_darling_errors!(GraphqlObjectMetaArgs::from_list(&attr_args));
 
-    // Parse the reference struct
-    let reference_struct = parse_macro_input!(input as ItemStruct);
+    // Parse the reference item
+    let reference_item = parse_macro_input!(input as Item);
 
     // Create output buffer
     let mut out = quote! {};
 
     // If this is a struct
-    // if let Some(reference_struct) = utils::get_item_struct(reference_item.clone()) {
-    // Generate the `SimpleObject` version of the struct
-    let o = generate_output_struct(&reference_struct, &options);
-    out = quote! {
-        #out
-        #o
-    };
+    if let Item::Struct(reference_struct) = &reference_item {
+        // Generate the `SimpleObject` version of the struct
+        let o = generate_output_struct(reference_struct, &options);
+        out = quote! {
+            #out
+            #o
+        };
+
+        // Generate the `InputObject` version of the struct
+        let o = generate_input_struct(reference_struct, &options);
+        out = quote! {
+            #out
+            #o
+        };
+
+    // If this is an enum
+    } else if let Item::Enum(reference_enum) = &reference_item {
+        // Generate the `Union` enum for use as a GraphQL output
+        let o = generate_output_enum(reference_enum, &options);
+        out = quote! {
+            #out
+            #o
+        };
+
+        // Generate the `OneofObject` enum for use as a GraphQL input
+        let o = handle_darling_errors!(generate_input_enum(reference_enum, &options));
+        out = quote! {
+            #out
+            #o
+        };
+
+    // Throw an error for everything else
+    } else {
+        out = quote! {
+            compile_error!("#[graphql_object] annotation can only be applied to structs and enums");
+        }
+    }
 
-    // Generate the `InputObject` version of the struct
-    let o = generate_input_struct(&reference_struct, &options);
-    out = quote! {
-        #out
-        #o
-    };
+    out.into()
+}
+
+/// A function-like macro that generates a Relay-style Cursor Connection for paginating a
+/// GraphQL list field of the given item type.
+///
+/// Given `graphql_connection!(User)` this generates:
+/// - `UserConnection`, a [`SimpleObject`] with `edges: Vec<UserEdge>` and `page_info: UserPageInfo`
+/// - `UserEdge`, a [`SimpleObject`] with `cursor: String` and `node: User`
+/// - `UserPageInfo`, a [`SimpleObject`] with `has_next_page`, `has_previous_page`,
+///   `start_cursor`, and `end_cursor`
+/// - `UserConnection::build(items, first, after, last, before)`, a builder that slices `items`
+///   according to the Relay `first`/`after`/`last`/`before` arguments and encodes each edge's
+///   cursor from its index
+///
+/// `PageInfo` is namespaced per item type (`UserPageInfo`, not a shared `PageInfo`) even though
+/// every copy has identical fields: `async_graphql`'s schema builder registers each `SimpleObject`
+/// under its Rust type's name, and rejects two distinct Rust types sharing a GraphQL name, so a
+/// single `PageInfo` regenerated by every invocation would only work for one paginated entity per
+/// schema. Giving each connection its own `<T>PageInfo` lets `graphql_connection!` be invoked for
+/// as many entities as the schema needs.
+#[proc_macro]
+pub fn graphql_connection(input: TokenStream) -> TokenStream {
+    // Parse the item type
+    let item_type = parse_macro_input!(input as Path);
+
+    let type_name = &item_type
+        .segments
+        .last()
+        .expect("expected a type path")
+        .ident;
+
+    let connection_ident = format_ident!("{}Connection", type_name);
+    let edge_ident = format_ident!("{}Edge", type_name);
+    let page_info_ident = format_ident!("{}PageInfo", type_name);
+
+    let out = quote! {
+        #[derive(::async_graphql::SimpleObject)]
+        pub struct #connection_ident {
+            pub edges: Vec<#edge_ident>,
+            pub page_info: #page_info_ident,
+        }
 
-    // // If this is an enum
-    // } else if let Some(reference_enum) = utils::get_item_enum(reference_item.clone()) {
-    //     // Generate the `Union` enum for use as a GraphQL output
-    //     let o = generate_output_enum(&reference_enum, &options);
-    //     out = quote! {
-    //         #out
-    //         #o
-    //     };
-
-    // // Throw an error for everything else
-    // } else {
-    //     out = quote! {
-    //         compile_error!("#[graphql_object] annotation can only be applied to structs and enums")
-    //     }
-    // }
+        #[derive(::async_graphql::SimpleObject)]
+        pub struct #edge_ident {
+            pub cursor: String,
+            pub node: #item_type,
+        }
+
+        #[derive(::async_graphql::SimpleObject)]
+        pub struct #page_info_ident {
+            pub has_next_page: bool,
+            pub has_previous_page: bool,
+            pub start_cursor: Option<String>,
+            pub end_cursor: Option<String>,
+        }
+
+        impl #connection_ident {
+            /// Slices `items` according to the Relay `first`/`after`/`last`/`before` cursor
+            /// arguments and assembles the resulting connection, encoding each edge's cursor
+            /// from its index.
+            pub fn build(
+                items: Vec<#item_type>,
+                first: Option<i32>,
+                after: Option<String>,
+                last: Option<i32>,
+                before: Option<String>,
+            ) -> Self {
+                fn encode_cursor(index: usize) -> String {
+                    ::base64::encode(index.to_string())
+                }
+
+                fn decode_cursor(cursor: &str) -> Option<usize> {
+                    ::base64::decode(cursor)
+                        .ok()
+                        .and_then(|bytes| String::from_utf8(bytes).ok())
+                        .and_then(|s| s.parse().ok())
+                }
+
+                let len = items.len();
+
+                let start = after
+                    .as_deref()
+                    .and_then(decode_cursor)
+                    .map(|i| i + 1)
+                    .unwrap_or(0)
+                    .min(len);
+                let end = before
+                    .as_deref()
+                    .and_then(decode_cursor)
+                    .unwrap_or(len)
+                    .min(len)
+                    .max(start);
+
+                let end = match first {
+                    Some(first) => end.min(start + first.max(0) as usize),
+                    None => end,
+                };
+                let start = match last {
+                    Some(last) => start.max(end.saturating_sub(last.max(0) as usize)),
+                    None => start,
+                };
+
+                let has_previous_page = start > 0;
+                let has_next_page = end < len;
+
+                let edges: Vec<#edge_ident> = items
+                    .into_iter()
+                    .enumerate()
+                    .skip(start)
+                    .take(end.saturating_sub(start))
+                    .map(|(index, node)| #edge_ident {
+                        cursor: encode_cursor(index),
+                        node,
+                    })
+                    .collect();
+
+                let start_cursor = edges.first().map(|edge| edge.cursor.clone());
+                let end_cursor = edges.last().map(|edge| edge.cursor.clone());
+
+                Self {
+                    edges,
+                    page_info: #page_info_ident {
+                        has_next_page,
+                        has_previous_page,
+                        start_cursor,
+                        end_cursor,
+                    },
+                }
+            }
+        }
+    };
 
     out.into()
 }
@@ -158,11 +369,43 @@ fn generate_output_struct(
     // Start with a copy of the reference struct
     let mut output_obj_struct = reference_struct.clone();
 
-    // Remove any `io_object` meta tags from the fields
-    for field in &mut output_obj_struct.fields {
-        utils::strip_annotations_with_path(format_ident!("graphql_object"), &mut field.attrs);
+    // Fields that should also expose a `derived` accessor, as (field name, derived spec) pairs
+    let mut derived_fields = Vec::new();
+
+    // Drop any fields that are only meant for the input object, and remove any `graphql_object`
+    // meta tags left over on the rest
+    if let Fields::Named(fields) = &mut output_obj_struct.fields {
+        let mut kept_fields = Punctuated::new();
+        for mut field in fields.named.clone() {
+            let args = handle_darling_errors!(GraphqlObjectFieldArgs::from_field(&field));
+
+            // Collect this field's `derived` accessors before deciding whether to drop the raw
+            // field itself, so `skip_output` can hide the raw value while still exposing it
+            // through its derived field(s) (e.g. store a `chrono::Duration` but surface it only
+            // as `duration_secs`)
+            let field_name = field.ident.clone().expect("Can't work with tuple structs");
+            for derived in &args.derived {
+                derived_fields.push((field_name.clone(), derived.clone()));
+            }
+
+            if args.skip_output {
+                if args.derived.is_empty() {
+                    continue;
+                }
+
+                // Keep the field so the `#[ComplexObject]` resolver below can still read it,
+                // but hide it from the `SimpleObject`'s own GraphQL fields
+                field.attrs.push(parse_quote!(#[graphql(skip)]));
+            }
+
+            utils::strip_annotations_with_path(format_ident!("graphql_object"), &mut field.attrs);
+            kept_fields.push(field);
+        }
+        fields.named = kept_fields;
     }
 
+    let has_derived_fields = !derived_fields.is_empty();
+
     let extra_derive = if !options.skip_derive_simple_object {
         quote! {
             #[derive(::async_graphql::SimpleObject)]
@@ -171,10 +414,46 @@ fn generate_output_struct(
         quote! {}
     };
 
+    // `SimpleObject` can't add computed fields on its own, so the derived accessors are added
+    // via a paired `#[ComplexObject]` impl block below, which requires `#[graphql(complex)]` on
+    // the struct regardless of who derives `SimpleObject` on it
+    let complex_attr = if has_derived_fields {
+        quote! { #[graphql(complex)] }
+    } else {
+        quote! {}
+    };
+
+    // Generate the `#[ComplexObject]` impl exposing each derived field as its own resolver
+    let complex_obj_impl = if has_derived_fields {
+        let output_ident = &output_obj_struct.ident;
+        let resolvers = derived_fields.iter().map(|(field_name, derived)| {
+            let resolver_name = format_ident!("{}", derived.name);
+            let target_type = &derived.into;
+
+            quote! {
+                async fn #resolver_name(&self) -> #target_type {
+                    self.#field_name.clone().into()
+                }
+            }
+        });
+
+        quote! {
+            #[::async_graphql::ComplexObject]
+            impl #output_ident {
+                #(#resolvers)*
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // output the struct unchanged, but with the extra simple object derive
     quote! {
         #extra_derive
+        #complex_attr
         #output_obj_struct
+
+        #complex_obj_impl
     }
 }
 
@@ -210,24 +489,78 @@ fn generate_input_struct(
         }
     }
 
-    // Loop through the fields and update them as necessary for the input type
-    for field in &mut input_obj_struct.fields {
-        let args = handle_darling_errors!(GraphqlObjectFieldArgs::from_field(&field));
+    // Follow the input type's own field-naming convention, independent of the output type
+    if let Some(rename_fields) = &options.rename_fields {
+        input_obj_struct
+            .attrs
+            .push(parse_quote!(#[graphql(rename_fields = #rename_fields)]));
+    }
 
-        // If this is a nested object that should be transformed to it's input object equivalent
-        if let Some(path) = args.input_type {
-            field.ty = TypePath { qself: None, path }.into();
-        }
+    // Loop through the fields, dropping any that are only meant for the output object and
+    // updating the rest as necessary for the input type
+    if let Fields::Named(fields) = &mut input_obj_struct.fields {
+        let mut kept_fields = Punctuated::new();
+        for mut field in fields.named.clone() {
+            let args = handle_darling_errors!(GraphqlObjectFieldArgs::from_field(&field));
 
-        // Remove any `io_object` annotation left over on the field
-        let mut new_attrs = Vec::new();
-        for attr in &field.attrs {
-            if attr.path.get_ident() != Some(&format_ident!("graphql_object")) {
-                new_attrs.push(attr.clone());
+            if args.skip_input {
+                continue;
+            }
+
+            // If this is a nested object that should be transformed to it's input object equivalent
+            if let Some(path) = args.input_type {
+                field.ty = TypePath { qself: None, path }.into();
+            }
+
+            // Remove any `graphql_object` annotation left over on the field
+            utils::strip_annotations_with_path(format_ident!("graphql_object"), &mut field.attrs);
+
+            // This field needs a different GraphQL name on the input side than whatever the
+            // output type's own `#[graphql(...)]` attributes (copied through above) specify. Any
+            // `name = "..."` already carried over from the output field has to go first, or
+            // `async_graphql`'s attribute parsing sees two `name` keys and rejects the field.
+            if let Some(input_name) = &args.input_name {
+                field.attrs = field
+                    .attrs
+                    .into_iter()
+                    .filter_map(|attr| {
+                        if attr.path.get_ident() != Some(&format_ident!("graphql")) {
+                            return Some(attr);
+                        }
+
+                        match attr.parse_meta() {
+                            Ok(Meta::List(list)) => {
+                                let nested: Vec<_> = list
+                                    .nested
+                                    .into_iter()
+                                    .filter(|nested| {
+                                        !matches!(
+                                            nested,
+                                            NestedMeta::Meta(Meta::NameValue(nv))
+                                                if nv.path.get_ident() == Some(&format_ident!("name"))
+                                        )
+                                    })
+                                    .collect();
+
+                                if nested.is_empty() {
+                                    None
+                                } else {
+                                    Some(parse_quote!(#[graphql(#(#nested),*)]))
+                                }
+                            }
+                            _ => Some(attr),
+                        }
+                    })
+                    .collect();
+
+                field
+                    .attrs
+                    .push(parse_quote!(#[graphql(name = #input_name)]));
             }
-        }
 
-        field.attrs = new_attrs;
+            kept_fields.push(field);
+        }
+        fields.named = kept_fields;
     }
 
     // Output input object struct
@@ -238,53 +571,220 @@ fn generate_input_struct(
         #input_obj_struct
     };
 
-    // Implement `Into<OriginalStruct> for OriginalStructInput`
+    // Implement `Into<OriginalStruct> for OriginalStructInput`, or, if `try_into` is set,
+    // `TryFrom<OriginalStructInput> for OriginalStruct`
     let orig_ident = &reference_struct.ident;
     let input_obj_ident = input_obj_struct.ident;
 
+    // The `TryFrom` impl takes its input by value as `value`; the `Into` impl reads it off `self`
+    let receiver: syn::Ident = if options.try_into {
+        format_ident!("value")
+    } else {
+        format_ident!("self")
+    };
+
+    let mut validations = Vec::new();
     let mut field_assignments = Vec::new();
 
     for field in &reference_struct.fields {
         let name = field.ident.as_ref().expect("Can't work with tuple structs");
+        let args = handle_darling_errors!(GraphqlObjectFieldArgs::from_field(field));
 
-        field_assignments.push(quote! {
-            #name: self.#name.into()
-        });
+        // `generate_output_struct` drops fields that are `skip_output` with no `derived`
+        // accessor from `#orig_ident`'s own definition entirely, so there's no field left here
+        // to assign into
+        if args.skip_output && args.derived.is_empty() {
+            continue;
+        }
+
+        // Fields skipped in the input object aren't present on the input value (`#receiver`),
+        // so they have to be filled in some other way
+        if args.skip_input {
+            let default_value = if let Some(default_fn) = &args.input_default {
+                quote! { #default_fn() }
+            } else {
+                quote! { ::std::default::Default::default() }
+            };
+
+            field_assignments.push(quote! {
+                #name: #default_value
+            });
+            continue;
+        }
+
+        if options.try_into {
+            if let Some(validate_fn) = &args.validate_with {
+                validations.push(quote! {
+                    #validate_fn(&#receiver.#name).map_err(::async_graphql::Error::new)?;
+                });
+            }
+
+            field_assignments.push(quote! {
+                #name: ::std::convert::TryInto::try_into(#receiver.#name)?
+            });
+        } else {
+            field_assignments.push(quote! {
+                #name: #receiver.#name.into()
+            });
+        }
     }
 
-    out = quote! {
-        #out
+    let conversion_impl = if options.try_into {
+        quote! {
+            impl ::std::convert::TryFrom<#input_obj_ident> for #orig_ident {
+                type Error = ::async_graphql::Error;
 
-        impl Into<#orig_ident> for #input_obj_ident {
-            fn into(self) -> #orig_ident {
-                #orig_ident {
-                    #(#field_assignments),*
+                fn try_from(#receiver: #input_obj_ident) -> ::std::result::Result<Self, Self::Error> {
+                    #(#validations)*
+
+                    Ok(#orig_ident {
+                        #(#field_assignments),*
+                    })
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl Into<#orig_ident> for #input_obj_ident {
+                fn into(#receiver) -> #orig_ident {
+                    #orig_ident {
+                        #(#field_assignments),*
+                    }
                 }
             }
         }
     };
 
+    out = quote! {
+        #out
+
+        #conversion_impl
+    };
+
     out.into()
 }
 
-// /// Generate the `SimpleObject` version of a struct
-// fn generate_output_enum(
-//     reference_enum: &ItemEnum,
-//     _options: &GraphqlObjectMetaArgs,
-// ) -> TokenStream2 {
-//     // Start with a copy of the reference struct
-//     let mut output_obj_enum = reference_enum.clone();
-
-//     let extra_derive = quote! {
-//         #[derive(::async_graphql::Union)]
-//     };
-
-//     // output the struct unchanged, but with the extra simple object derive
-//     quote! {
-//         #extra_derive
-//         #output_obj_enum
-//     }
-// }
+/// Generate the `Union` version of an enum
+fn generate_output_enum(
+    reference_enum: &ItemEnum,
+    _options: &GraphqlObjectMetaArgs,
+) -> TokenStream2 {
+    // Start with a copy of the reference enum
+    let mut output_obj_enum = reference_enum.clone();
+
+    // Remove any `graphql_object` meta tags from the variants and their inner fields; the
+    // `input_type` override in particular is meaningless on the output side, which keeps the
+    // original payload type
+    for variant in &mut output_obj_enum.variants {
+        utils::strip_annotations_with_path(format_ident!("graphql_object"), &mut variant.attrs);
+
+        for field in &mut variant.fields {
+            utils::strip_annotations_with_path(format_ident!("graphql_object"), &mut field.attrs);
+        }
+    }
+
+    // output the enum unchanged, but with the extra union derive
+    quote! {
+        #[derive(::async_graphql::Union)]
+        #output_obj_enum
+    }
+}
+
+/// Generate the `OneofObject` of a generated enum
+fn generate_input_enum(
+    reference_enum: &ItemEnum,
+    options: &GraphqlObjectMetaArgs,
+) -> Result<TokenStream2, darling::Error> {
+    // ouput a copy of the enum for the input type
+    let mut input_obj_enum = reference_enum.clone();
+    input_obj_enum.ident = format_ident!(
+        "{}",
+        &options
+            .input_type_name
+            .as_ref()
+            .unwrap_or(&format!("{}Input", reference_enum.ident))
+    );
+
+    // Update the input enum doc string if necessary
+    if let Some(input_doc) = &options.input_type_doc {
+        if let Some(doc) = input_obj_enum
+            .attrs
+            .iter_mut()
+            .filter(|x| x.path.get_ident() == Some(&format_ident!("doc")))
+            .next()
+        {
+            let input_doc = input_doc;
+
+            doc.tokens = quote! { = #input_doc};
+        }
+    }
+
+    // Implement `Into<OriginalEnum> for OriginalEnumInput`
+    let orig_ident = &reference_enum.ident;
+    let input_obj_ident = &input_obj_enum.ident;
+
+    let mut match_arms = Vec::new();
+
+    // Walk the original variants and the copied ones together: each variant's single field may
+    // carry its own `input_type`, just like a struct field does, so a variant wrapping a
+    // `#[graphql_object]`-generated payload (e.g. `Create(CreateUserPayload)`) can point its
+    // `OneofObject` copy at that payload's generated `...Input` type instead of reusing the
+    // output type, which wouldn't implement `InputType`.
+    for (reference_variant, input_variant) in reference_enum
+        .variants
+        .iter()
+        .zip(input_obj_enum.variants.iter_mut())
+    {
+        let variant_ident = &reference_variant.ident;
+
+        utils::strip_annotations_with_path(
+            format_ident!("graphql_object"),
+            &mut input_variant.attrs,
+        );
+
+        let reference_field = match &reference_variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => {
+                return Err(darling::Error::custom(
+                    "#[graphql_object] on an enum requires every variant to have exactly one unnamed field",
+                )
+                .with_span(variant_ident));
+            }
+        };
+
+        let args = GraphqlObjectFieldArgs::from_field(reference_field)?;
+
+        if let Fields::Unnamed(input_fields) = &mut input_variant.fields {
+            let input_field = &mut input_fields.unnamed[0];
+
+            if let Some(path) = args.input_type {
+                input_field.ty = TypePath { qself: None, path }.into();
+            }
+
+            utils::strip_annotations_with_path(
+                format_ident!("graphql_object"),
+                &mut input_field.attrs,
+            );
+        }
+
+        match_arms.push(quote! {
+            #input_obj_ident::#variant_ident(v) => #orig_ident::#variant_ident(v.into())
+        });
+    }
+
+    Ok(quote! {
+        #[derive(::async_graphql::OneofObject)]
+        #input_obj_enum
+
+        impl Into<#orig_ident> for #input_obj_ident {
+            fn into(self) -> #orig_ident {
+                match self {
+                    #(#match_arms),*
+                }
+            }
+        }
+    })
+}
 
 mod utils {
     use syn::{Attribute, Ident};
@@ -299,36 +799,4 @@ mod utils {
 
         *attrs = new_attrs;
     }
-
-    // pub fn get_item_struct(derive_input: DeriveInput) -> Option<ItemStruct> {
-    //     match derive_input.data {
-    //         syn::Data::Struct(reference_struct) => Some(ItemStruct {
-    //             attrs: derive_input.attrs,
-    //             generics: derive_input.generics,
-    //             ident: derive_input.ident,
-    //             vis: derive_input.vis,
-    //             fields: reference_struct.fields,
-    //             semi_token: reference_struct.semi_token,
-    //             struct_token: reference_struct.struct_token,
-    //         }),
-    //         syn::Data::Enum(_) => None,
-    //         syn::Data::Union(_) => None,
-    //     }
-    // }
-
-    // pub fn get_item_enum(derive_input: DeriveInput) -> Option<ItemEnum> {
-    //     match derive_input.data {
-    //         syn::Data::Enum(reference_enum) => Some(ItemEnum {
-    //             attrs: derive_input.attrs,
-    //             generics: derive_input.generics,
-    //             ident: derive_input.ident,
-    //             vis: derive_input.vis,
-    //             enum_token: reference_enum.enum_token,
-    //             brace_token: reference_enum.brace_token,
-    //             variants: reference_enum.variants,
-    //         }),
-    //         syn::Data::Struct(_) => None,
-    //         syn::Data::Union(_) => None,
-    //     }
-    // }
 }